@@ -0,0 +1,93 @@
+//! `Bundle` itself can't be turned into a trait object: `from_components` is generic over an
+//! arbitrary closure argument, and `get_components` consumes `self` by value. Neither of those
+//! is object-safe. `ApplicableBundle` sidesteps the problem by forgetting the component-id
+//! machinery entirely, and only exposing "apply this bundle to an entity that already exists".
+
+use bevy::ecs::system::EntityCommands;
+use bevy::ecs::world::EntityWorldMut;
+use bevy::prelude::*;
+
+/// An object-safe stand-in for [`Bundle`], usable as `Box<dyn ApplicableBundle>`.
+///
+/// Unlike `Bundle`, this can't be used to spawn a new entity from scratch: it only knows how to
+/// insert itself onto an entity that's already been spawned.
+pub(crate) trait ApplicableBundle {
+    /// Inserts the bundle into `entity` via direct world access.
+    fn apply_to_world(self: Box<Self>, entity: &mut EntityWorldMut);
+
+    /// Inserts the bundle into `entity`, queuing the insertion as a command.
+    fn apply_to_commands(self: Box<Self>, entity: &mut EntityCommands);
+}
+
+impl<B: Bundle> ApplicableBundle for B {
+    fn apply_to_world(self: Box<Self>, entity: &mut EntityWorldMut) {
+        entity.insert(*self);
+    }
+
+    fn apply_to_commands(self: Box<Self>, entity: &mut EntityCommands) {
+        entity.insert(*self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ext::{EntityCommandsExt, EntityWorldMutExt};
+    use crate::{ComponentStrategy, A, B};
+
+    /// Following the compiler's suggestion from `impl_bundle_return_type` and reaching for
+    /// `Box<dyn Bundle>` doesn't work, since `Bundle` isn't object-safe. `Box<dyn
+    /// ApplicableBundle>` is the object-safe replacement: each match arm boxes up a different
+    /// concrete bundle, and the caller doesn't need to know which.
+    #[test]
+    fn impl_boxed_applicable_bundle_return_type() {
+        fn spawn_bundle_naive(strategy: &ComponentStrategy) -> Box<dyn ApplicableBundle> {
+            match strategy {
+                ComponentStrategy::A => Box::new((A,)),
+                ComponentStrategy::B => Box::new((B,)),
+                ComponentStrategy::AAndB => Box::new((A, B)),
+            }
+        }
+
+        let mut world = World::new();
+
+        let mut entity_a = world.spawn_empty();
+        entity_a.insert_boxed(spawn_bundle_naive(&ComponentStrategy::A));
+
+        let mut entity_b = world.spawn_empty();
+        entity_b.insert_boxed(spawn_bundle_naive(&ComponentStrategy::B));
+
+        let mut entity_a_and_b = world.spawn_empty();
+        entity_a_and_b.insert_boxed(spawn_bundle_naive(&ComponentStrategy::AAndB));
+    }
+
+    /// The same `Box<dyn ApplicableBundle>` return type works equally well behind `Commands`,
+    /// via `insert_boxed` on `EntityCommands`.
+    #[test]
+    fn insert_boxed_via_commands() {
+        use bevy::ecs::system::RunSystemOnce;
+
+        fn spawn_bundle_naive(strategy: &ComponentStrategy) -> Box<dyn ApplicableBundle> {
+            match strategy {
+                ComponentStrategy::A => Box::new((A,)),
+                ComponentStrategy::B => Box::new((B,)),
+                ComponentStrategy::AAndB => Box::new((A, B)),
+            }
+        }
+
+        let mut world = World::new();
+
+        fn my_system(mut commands: Commands) {
+            let mut entity_a = commands.spawn_empty();
+            entity_a.insert_boxed(spawn_bundle_naive(&ComponentStrategy::A));
+
+            let mut entity_b = commands.spawn_empty();
+            entity_b.insert_boxed(spawn_bundle_naive(&ComponentStrategy::B));
+
+            let mut entity_a_and_b = commands.spawn_empty();
+            entity_a_and_b.insert_boxed(spawn_bundle_naive(&ComponentStrategy::AAndB));
+        }
+
+        world.run_system_once(my_system);
+    }
+}
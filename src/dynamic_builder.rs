@@ -0,0 +1,147 @@
+//! Every strategy in the crate root decides the full component set in one `match`. `DynamicBundleBuilder`
+//! instead lets callers push components one at a time -- conditionally, in loops, from game state
+//! flags -- and materialize them onto an entity in a single pass. `spawn_dynamic_batch` extends
+//! the same idea to `Commands::spawn_batch`, which normally requires every spawned entity to
+//! share one `Bundle` type.
+
+use bevy::ecs::system::{Commands, EntityCommands};
+use bevy::ecs::world::World;
+
+use crate::applicable_bundle::ApplicableBundle;
+use crate::ext::EntityCommandsExt;
+
+/// Accumulates components to apply to an entity, without committing to a fixed `Bundle` type
+/// up front.
+#[derive(Default)]
+pub(crate) struct DynamicBundleBuilder {
+    bundles: Vec<Box<dyn ApplicableBundle>>,
+}
+
+impl DynamicBundleBuilder {
+    /// Creates an empty builder.
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queues `bundle` to be applied to the entity once this builder is built.
+    pub(crate) fn add(mut self, bundle: impl ApplicableBundle + 'static) -> Self {
+        self.bundles.push(Box::new(bundle));
+        self
+    }
+
+    /// Applies every queued bundle to `entity`, in the order they were added.
+    pub(crate) fn build(self, entity: &mut EntityCommands) {
+        for bundle in self.bundles {
+            entity.insert_boxed(bundle);
+        }
+    }
+}
+
+/// Spawns entities from a [`DynamicBundleBuilder`] or from a batch of boxed [`ApplicableBundle`]s.
+pub(crate) trait CommandsExt {
+    /// Spawns a new entity and applies `builder`'s accumulated components to it.
+    fn spawn_dynamic(&mut self, builder: DynamicBundleBuilder) -> EntityCommands;
+
+    /// Spawns one entity per item in `bundles`, applying that item's components.
+    ///
+    /// Unlike `Commands::spawn_batch`, each item may apply a different set of components, since
+    /// they're type-erased rather than all sharing one `Bundle` type.
+    fn spawn_dynamic_batch(
+        &mut self,
+        bundles: impl IntoIterator<Item = Box<dyn ApplicableBundle + Send>> + Send + 'static,
+    );
+}
+
+impl CommandsExt for Commands<'_, '_> {
+    fn spawn_dynamic(&mut self, builder: DynamicBundleBuilder) -> EntityCommands {
+        let mut entity = self.spawn_empty();
+        builder.build(&mut entity);
+        entity
+    }
+
+    fn spawn_dynamic_batch(
+        &mut self,
+        bundles: impl IntoIterator<Item = Box<dyn ApplicableBundle + Send>> + Send + 'static,
+    ) {
+        // Reserve every entity up front, then apply all the bundles in a single queued command,
+        // so this stays efficient for large batches instead of queuing one command per entity.
+        let entities_and_bundles: Vec<_> = bundles
+            .into_iter()
+            .map(|bundle| (self.reserve_entity(), bundle))
+            .collect();
+
+        self.queue(move |world: &mut World| {
+            for (entity, bundle) in entities_and_bundles {
+                let mut entity_world_mut = world.entity_mut(entity);
+                bundle.apply_to_world(&mut entity_world_mut);
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::prelude::*;
+
+    use crate::{A, B};
+
+    /// Whether to add `A` and/or `B` can be decided incrementally, e.g. from flags computed
+    /// elsewhere in a system, rather than enumerated up front as a `ComponentStrategy`.
+    #[test]
+    fn build_from_conditional_flags() {
+        let mut world = World::new();
+
+        fn my_system(mut commands: Commands) {
+            let has_a = true;
+            let has_b = false;
+
+            let mut builder = DynamicBundleBuilder::new();
+            if has_a {
+                builder = builder.add(A);
+            }
+            if has_b {
+                builder = builder.add(B);
+            }
+
+            commands.spawn_dynamic(builder);
+        }
+
+        world.run_system_once(my_system);
+        assert_eq!(world.query::<&A>().iter(&world).count(), 1);
+        assert_eq!(world.query::<&B>().iter(&world).count(), 0);
+    }
+
+    #[test]
+    fn build_with_both_components() {
+        let mut world = World::new();
+
+        fn my_system(mut commands: Commands) {
+            let builder = DynamicBundleBuilder::new().add(A).add(B);
+            commands.spawn_dynamic(builder);
+        }
+
+        world.run_system_once(my_system);
+        assert_eq!(world.query::<&A>().iter(&world).count(), 1);
+        assert_eq!(world.query::<&B>().iter(&world).count(), 1);
+    }
+
+    /// Stock `Commands::spawn_batch` requires every item to be the same `Bundle` type.
+    /// `spawn_dynamic_batch` accepts a heterogeneous mix, one boxed `ApplicableBundle` per entity.
+    #[test]
+    fn spawn_dynamic_batch_with_mixed_bundles() {
+        let mut world = World::new();
+
+        fn my_system(mut commands: Commands) {
+            let bundles: Vec<Box<dyn ApplicableBundle + Send>> =
+                vec![Box::new((A,)), Box::new((B,)), Box::new((A, B))];
+            commands.spawn_dynamic_batch(bundles);
+        }
+
+        world.run_system_once(my_system);
+        assert_eq!(world.query::<Entity>().iter(&world).count(), 3);
+        assert_eq!(world.query::<&A>().iter(&world).count(), 2);
+        assert_eq!(world.query::<&B>().iter(&world).count(), 2);
+    }
+}
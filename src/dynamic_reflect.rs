@@ -0,0 +1,122 @@
+//! `ComponentStrategy` hard-codes every arm at compile time. This module adds a path that
+//! spawns from a runtime list of reflected components instead, so a strategy can be loaded from
+//! config or serialized data rather than baked into code.
+
+use bevy::ecs::system::EntityCommands;
+use bevy::prelude::*;
+use bevy::reflect::Reflect;
+
+/// Inserts a runtime list of reflected components onto an entity, mirroring Bevy's own
+/// `insert_reflect` approach: each value is looked up in the `AppTypeRegistry` to find its
+/// `ReflectComponent` data, and that's used to perform the insertion.
+pub(crate) trait EntityCommandsDynamicReflectExt {
+    /// Inserts each boxed `Reflect` value as a component, queuing the work as a command.
+    fn insert_dynamic_reflect(
+        &mut self,
+        components: Vec<Box<dyn Reflect>>,
+        type_registry: &AppTypeRegistry,
+    ) -> &mut Self;
+}
+
+impl EntityCommandsDynamicReflectExt for EntityCommands<'_, '_, '_> {
+    fn insert_dynamic_reflect(
+        &mut self,
+        components: Vec<Box<dyn Reflect>>,
+        type_registry: &AppTypeRegistry,
+    ) -> &mut Self {
+        let entity = self.id();
+        let type_registry = type_registry.clone();
+
+        self.commands().queue(move |world: &mut World| {
+            let registry = type_registry.read();
+            let mut entity_mut = world.entity_mut(entity);
+
+            for component in components {
+                let reflect_component = registry
+                    .get_type_data::<ReflectComponent>(component.as_any().type_id())
+                    .unwrap_or_else(|| {
+                        panic!(
+                            "`{}` is not registered as a `Component`",
+                            component.reflect_type_path()
+                        )
+                    });
+
+                reflect_component.insert(&mut entity_mut, component.as_ref(), &registry);
+            }
+        });
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    use crate::{A, B};
+
+    fn type_registry_with_a_and_b() -> AppTypeRegistry {
+        let type_registry = AppTypeRegistry::default();
+        {
+            let mut registry = type_registry.write();
+            registry.register::<A>();
+            registry.register::<B>();
+        }
+        type_registry
+    }
+
+    #[test]
+    fn spawn_a_only_from_reflect() {
+        let mut world = World::new();
+        let type_registry = type_registry_with_a_and_b();
+        world.insert_resource(type_registry.clone());
+
+        fn my_system(mut commands: Commands, type_registry: Res<AppTypeRegistry>) {
+            let components: Vec<Box<dyn Reflect>> = vec![Box::new(A)];
+            commands
+                .spawn_empty()
+                .insert_dynamic_reflect(components, &type_registry);
+        }
+
+        world.run_system_once(my_system);
+        assert_eq!(world.query::<&A>().iter(&world).count(), 1);
+        assert_eq!(world.query::<&B>().iter(&world).count(), 0);
+    }
+
+    #[test]
+    fn spawn_b_only_from_reflect() {
+        let mut world = World::new();
+        let type_registry = type_registry_with_a_and_b();
+        world.insert_resource(type_registry.clone());
+
+        fn my_system(mut commands: Commands, type_registry: Res<AppTypeRegistry>) {
+            let components: Vec<Box<dyn Reflect>> = vec![Box::new(B)];
+            commands
+                .spawn_empty()
+                .insert_dynamic_reflect(components, &type_registry);
+        }
+
+        world.run_system_once(my_system);
+        assert_eq!(world.query::<&A>().iter(&world).count(), 0);
+        assert_eq!(world.query::<&B>().iter(&world).count(), 1);
+    }
+
+    #[test]
+    fn spawn_a_and_b_from_reflect() {
+        let mut world = World::new();
+        let type_registry = type_registry_with_a_and_b();
+        world.insert_resource(type_registry.clone());
+
+        fn my_system(mut commands: Commands, type_registry: Res<AppTypeRegistry>) {
+            let components: Vec<Box<dyn Reflect>> = vec![Box::new(A), Box::new(B)];
+            commands
+                .spawn_empty()
+                .insert_dynamic_reflect(components, &type_registry);
+        }
+
+        world.run_system_once(my_system);
+        assert_eq!(world.query::<&A>().iter(&world).count(), 1);
+        assert_eq!(world.query::<&B>().iter(&world).count(), 1);
+    }
+}
@@ -0,0 +1,33 @@
+//! Extension traits for `EntityWorldMut` and `EntityCommands` that accumulate as this crate
+//! explores new ways to apply dynamically-determined components to an entity.
+
+use bevy::ecs::system::EntityCommands;
+use bevy::ecs::world::EntityWorldMut;
+
+use crate::applicable_bundle::ApplicableBundle;
+
+/// Lets an already-spawned entity accept a type-erased [`ApplicableBundle`].
+pub(crate) trait EntityWorldMutExt {
+    /// Applies a boxed bundle to this entity via direct world access.
+    fn insert_boxed(&mut self, bundle: Box<dyn ApplicableBundle>) -> &mut Self;
+}
+
+impl EntityWorldMutExt for EntityWorldMut<'_> {
+    fn insert_boxed(&mut self, bundle: Box<dyn ApplicableBundle>) -> &mut Self {
+        bundle.apply_to_world(self);
+        self
+    }
+}
+
+/// Lets an already-spawned entity accept a type-erased [`ApplicableBundle`] via `Commands`.
+pub(crate) trait EntityCommandsExt {
+    /// Applies a boxed bundle to this entity, queuing the insertion as a command.
+    fn insert_boxed(&mut self, bundle: Box<dyn ApplicableBundle>) -> &mut Self;
+}
+
+impl EntityCommandsExt for EntityCommands<'_, '_, '_> {
+    fn insert_boxed(&mut self, bundle: Box<dyn ApplicableBundle>) -> &mut Self {
+        bundle.apply_to_commands(self);
+        self
+    }
+}
@@ -11,10 +11,18 @@
 
 use bevy::prelude::*;
 
-#[derive(Component)]
+mod applicable_bundle;
+mod dynamic_builder;
+mod dynamic_reflect;
+mod ext;
+mod reborrow;
+
+#[derive(Component, Reflect)]
+#[reflect(Component)]
 struct A;
 
-#[derive(Component)]
+#[derive(Component, Reflect)]
+#[reflect(Component)]
 struct B;
 
 #[allow(dead_code)]
@@ -54,6 +62,10 @@ fn impl_boxed_bundle_return_type() {
 }
 */
 
+// `Bundle` isn't object-safe, but we can define our own trait that is.
+// See the `applicable_bundle` module for an `ApplicableBundle` trait that consumes `Box<Self>`
+// and applies itself to an already-spawned entity, turning the attempt above into working code.
+
 /// We can brute force this, by operating on the world directly.
 ///
 /// This works, but requires blocking access.
@@ -187,16 +199,21 @@ fn entity_commands_simple_extension() {
 /// Instead, let's pass in a closure into our extension method,
 /// which controls which builder we're using.
 ///
-/// Elaborate setup, but very flexible and quite comforable to use.
+/// The original version of this threaded a `&mut EntityCommands<'_, '_, '_>` through the closure,
+/// which drags a lifetime soup into every builder signature. `Reborrow` lets the closure take
+/// `EntityCommands` *by value* instead: the extension method reborrows its own handle with a
+/// shortened lifetime to hand to the closure, while keeping its original handle usable afterwards.
 #[test]
 fn entity_commands_closure_extension() {
     use bevy::ecs::system::{EntityCommands, RunSystemOnce};
 
+    use crate::reborrow::Reborrow;
+
     trait EntityCommandsExt<Config> {
         fn spawn_dynamic_bundle(
             &mut self,
             config: Config,
-            f: impl FnOnce(Config, &mut Self),
+            f: impl FnOnce(Config, EntityCommands),
         ) -> &mut Self;
     }
 
@@ -204,14 +221,14 @@ fn entity_commands_closure_extension() {
         fn spawn_dynamic_bundle(
             &mut self,
             config: Config,
-            f: impl FnOnce(Config, &mut Self),
+            f: impl FnOnce(Config, EntityCommands),
         ) -> &mut Self {
-            f(config, self);
+            f(config, self.reborrow());
             self
         }
     }
 
-    fn my_dynamic_builder(strategy: ComponentStrategy, commands: &mut EntityCommands<'_, '_, '_>) {
+    fn my_dynamic_builder(strategy: ComponentStrategy, mut commands: EntityCommands) {
         match strategy {
             ComponentStrategy::A => commands.insert(A),
             ComponentStrategy::B => commands.insert(B),
@@ -0,0 +1,30 @@
+//! `ref_mut_commands` and `ref_mut_entity_commands` both have to thread a `&mut Commands` /
+//! `&mut EntityCommands` through every helper signature, picking up an extra lifetime parameter
+//! in the process. `Commands` already exposes a `reborrow` method for exactly this; this module
+//! adds the `EntityCommands` equivalent, so dynamic-builder functions can take either handle *by
+//! value* while the caller keeps using its own handle afterwards.
+
+use bevy::ecs::system::EntityCommands;
+
+/// Produces a fresh, owned handle with a lifetime borrowed from `&mut self`.
+pub(crate) trait Reborrow {
+    /// The reborrowed type, tied to the lifetime of the `&mut self` reference.
+    type Reborrowed<'a>
+    where
+        Self: 'a;
+
+    /// Reborrows `self`, producing an owned handle that can be passed around by value.
+    fn reborrow(&mut self) -> Self::Reborrowed<'_>;
+}
+
+impl<'w, 's> Reborrow for EntityCommands<'w, 's, '_> {
+    type Reborrowed<'a>
+        = EntityCommands<'w, 's, 'a>
+    where
+        Self: 'a;
+
+    fn reborrow(&mut self) -> Self::Reborrowed<'_> {
+        let entity = self.id();
+        self.commands().entity(entity)
+    }
+}